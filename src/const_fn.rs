@@ -0,0 +1,148 @@
+//! `const fn` splitting for compile-time contexts (e.g. deriving a name from
+//! `file!()`, or processing a `&'static str` table), where the trait-based
+//! [`SplitLast`](crate::SplitLast) API can't be used because `Result` and
+//! iterators aren't available in `const` evaluation.
+//!
+//! These functions use only byte-slice indexing and manual loops, in the
+//! style of the `konst` crate's hand-rolled const parsers.
+
+/// Split `haystack` on the last occurrence of `delim`, after stripping a
+/// single trailing `delim` if present, and return the final segment.
+///
+/// Returns the full (stripped) `haystack` if `delim` never occurs, and
+/// returns `""` if `haystack` is empty.
+///
+/// ```rust
+/// use split_last::split_last_str;
+///
+/// const LAST: &str = split_last_str("/some/long/test/", "/");
+/// assert_eq!(LAST, "test");
+/// ```
+pub const fn split_last_str<'a>(haystack: &'a str, delim: &str) -> &'a str {
+    let bytes = haystack.as_bytes();
+    let delim_bytes = delim.as_bytes();
+
+    if bytes.is_empty() || delim_bytes.is_empty() {
+        return haystack;
+    }
+
+    let end = strip_trailing_delim(bytes, delim_bytes);
+    let start = rfind_delim_end(bytes, end, delim_bytes);
+
+    // SAFETY: `start` and `end` are always byte offsets that fall on
+    // delimiter boundaries or the ends of `bytes`, which (since `bytes` is
+    // the UTF-8 encoding of `haystack` and `delim_bytes` is the encoding of
+    // a full `char`/`&str`) are always UTF-8 char boundaries.
+    unsafe { core::str::from_utf8_unchecked(slice(bytes, start, end)) }
+}
+
+/// Like [`split_last_str`], but splits on a single `char` delimiter.
+///
+/// ```rust
+/// use split_last::split_last_char;
+///
+/// const LAST: &str = split_last_char("/some/long/test/", '/');
+/// assert_eq!(LAST, "test");
+/// ```
+pub const fn split_last_char(haystack: &str, delim: char) -> &str {
+    let mut buf = [0u8; 4];
+    let delim_str = delim.encode_utf8(&mut buf);
+    split_last_str(haystack, delim_str)
+}
+
+/// Strip one trailing occurrence of `delim` from `bytes`, returning the
+/// (possibly shortened) end index.
+const fn strip_trailing_delim(bytes: &[u8], delim: &[u8]) -> usize {
+    let len = bytes.len();
+    if len >= delim.len() && slice_eq(bytes, len - delim.len(), delim) {
+        len - delim.len()
+    } else {
+        len
+    }
+}
+
+/// Scan `bytes[..end]` backwards for the last occurrence of `delim`,
+/// returning the byte offset just past the match, or `0` if not found.
+const fn rfind_delim_end(bytes: &[u8], end: usize, delim: &[u8]) -> usize {
+    if delim.len() > end {
+        return 0;
+    }
+
+    let mut i = end - delim.len();
+    loop {
+        if slice_eq(bytes, i, delim) {
+            return i + delim.len();
+        }
+        if i == 0 {
+            return 0;
+        }
+        i -= 1;
+    }
+}
+
+/// `bytes[at..at + delim.len()] == delim`, without slice comparison operators.
+const fn slice_eq(bytes: &[u8], at: usize, delim: &[u8]) -> bool {
+    let mut i = 0;
+    while i < delim.len() {
+        if bytes[at + i] != delim[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `&bytes[start..end]`, spelled out so it can run in `const fn`.
+const fn slice(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    let (_, rest) = bytes.split_at(start);
+    let (last, _) = rest.split_at(end - start);
+    last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_last_str() {
+        assert_eq!(split_last_str("/some/long/test/", "/"), "test");
+        assert_eq!(split_last_str("test", "/"), "test");
+        assert_eq!(split_last_str("a/b/c", "/"), "c");
+    }
+
+    #[test]
+    fn test_split_last_str_empty_haystack() {
+        assert_eq!(split_last_str("", "/"), "");
+    }
+
+    #[test]
+    fn test_split_last_str_delimiter_absent() {
+        assert_eq!(split_last_str("no-delimiter-here", "/"), "no-delimiter-here");
+    }
+
+    #[test]
+    fn test_split_last_str_multi_byte_delimiter() {
+        assert_eq!(split_last_str("a::b::c", "::"), "c");
+        assert_eq!(split_last_str("a::b::c::", "::"), "c");
+        assert_eq!(split_last_str("no-delimiter-here", "::"), "no-delimiter-here");
+    }
+
+    #[test]
+    fn test_split_last_char() {
+        assert_eq!(split_last_char("/some/long/test/", '/'), "test");
+        assert_eq!(split_last_char("test", '/'), "test");
+    }
+
+    #[test]
+    fn test_split_last_char_non_ascii_delimiter() {
+        assert_eq!(split_last_char("a/é/b", 'é'), "/b");
+        assert_eq!(split_last_char("aé", 'é'), "a");
+    }
+
+    const CONST_CONTEXT: &str = split_last_str("/some/long/test/", "/");
+
+    #[test]
+    fn test_split_last_str_in_const_context() {
+        assert_eq!(CONST_CONTEXT, "test");
+    }
+}
@@ -0,0 +1,25 @@
+/// Demonstrate how to create a custom error
+/// Implement Display and Debug for a tuple containing a single string.
+///
+pub struct SplitError(
+    /// The error message that is displayed
+    pub String,
+);
+
+impl std::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0) // user-facing output
+    }
+}
+
+impl std::fmt::Debug for SplitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ file: {}, line: {}, error: {}}}",
+            file!(),
+            line!(),
+            self.0
+        ) // programmer-facing output
+    }
+}
@@ -0,0 +1,166 @@
+//! A reverse, segment-at-a-time iterator over [`SplitLast`](crate::SplitLast)
+//! splits, exposing the still-unsplit remainder the way std's
+//! `str::split`/`rsplit` family does via `as_str`/`remainder`.
+use std::marker::PhantomData;
+
+use crate::{SplitError, SplitPattern};
+
+/// An iterator that yields segments of a string from the end, one at a time,
+/// stripping a single trailing delimiter before the first segment.
+///
+/// Created by [`RSplitLast::rsplit_all`].
+pub struct SplitLastIter<'a, P> {
+    remainder: Option<&'a str>,
+    pattern: SplitPattern<'a>,
+    stripped_trailing: bool,
+    _pattern_kind: PhantomData<P>,
+}
+
+impl<'a, P> SplitLastIter<'a, P> {
+    fn new(haystack: &'a str, pattern: SplitPattern<'a>) -> Self {
+        Self {
+            remainder: Some(haystack),
+            pattern,
+            stripped_trailing: false,
+            _pattern_kind: PhantomData,
+        }
+    }
+
+    /// The portion of the original string not yet consumed by `next`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use split_last::RSplitLast;
+    ///
+    /// let mut it = "a/b/c/d".rsplit_all('/');
+    /// assert_eq!(it.next(), Some("d"));
+    /// assert_eq!(it.remainder(), Some("a/b/c"));
+    /// ```
+    pub fn remainder(&self) -> Option<&'a str> {
+        self.remainder
+    }
+}
+
+impl<'a, P> Iterator for SplitLastIter<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let haystack = self.remainder?;
+
+        let haystack = if self.stripped_trailing {
+            haystack
+        } else {
+            self.stripped_trailing = true;
+            self.pattern.strip_suffix_of(haystack)
+        };
+
+        match self.pattern.rfind_match(haystack) {
+            Some((start, end)) => {
+                self.remainder = Some(&haystack[..start]);
+                Some(&haystack[end..])
+            }
+            None => {
+                self.remainder = None;
+                Some(haystack)
+            }
+        }
+    }
+}
+
+/// Reverse-iteration companion to [`SplitLast`](crate::SplitLast), for
+/// peeling off trailing segments one at a time while keeping access to the
+/// unconsumed prefix.
+pub trait RSplitLast<'a, P>
+where
+    P: Into<SplitPattern<'a>>,
+{
+    type Error;
+
+    /// Returns an iterator over the segments of `self`, from the end,
+    /// stripping a single trailing delimiter first.
+    fn rsplit_all(&'a self, pat: P) -> SplitLastIter<'a, P>;
+
+    /// Returns the last `n` segments of `self`, in source order, after
+    /// stripping a single trailing delimiter.
+    ///
+    /// Errors if `self` doesn't contain at least `n` segments.
+    ///
+    /// Example:
+    /// ```rust
+    /// use split_last::RSplitLast;
+    ///
+    /// let last_two = "a/b/c/d".split_last_n('/', 2).expect("oops");
+    /// assert_eq!(last_two, vec!["c", "d"]);
+    /// ```
+    fn split_last_n(&'a self, pat: P, n: usize) -> Result<Vec<&'a str>, Self::Error>;
+}
+
+impl<'a, P> RSplitLast<'a, P> for &'a str
+where
+    P: Into<SplitPattern<'a>>,
+{
+    type Error = SplitError;
+
+    fn rsplit_all(&'a self, pat: P) -> SplitLastIter<'a, P> {
+        SplitLastIter::new(self, pat.into())
+    }
+
+    fn split_last_n(&'a self, pat: P, n: usize) -> Result<Vec<&'a str>, Self::Error> {
+        let mut segments = Vec::with_capacity(n);
+        let mut iter = self.rsplit_all(pat);
+
+        for _ in 0..n {
+            match iter.next() {
+                Some(segment) => segments.push(segment),
+                None => {
+                    return Err(SplitError(format!(
+                        "Expected at least {n} segments, found {}",
+                        segments.len()
+                    )))
+                }
+            }
+        }
+
+        segments.reverse();
+        Ok(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsplit_all() {
+        let mut it = "a/b/c/d".rsplit_all('/');
+        assert_eq!(it.next(), Some("d"));
+        assert_eq!(it.remainder(), Some("a/b/c"));
+        assert_eq!(it.next(), Some("c"));
+        assert_eq!(it.next(), Some("b"));
+        assert_eq!(it.next(), Some("a"));
+        assert_eq!(it.remainder(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_rsplit_all_trailing_delimiter() {
+        let mut it = "/some/long/test/".rsplit_all('/');
+        assert_eq!(it.next(), Some("test"));
+        assert_eq!(it.remainder(), Some("/some/long"));
+    }
+
+    #[test]
+    fn test_split_last_n() {
+        let last_two = "a/b/c/d".split_last_n('/', 2).expect("oops");
+        assert_eq!(last_two, vec!["c", "d"]);
+
+        let all = "a/b/c/d".split_last_n('/', 4).expect("oops");
+        assert_eq!(all, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_split_last_n_not_enough_segments() {
+        let err = "a/b".split_last_n('/', 5);
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! The original `Pattern`-generic implementation, kept available behind the
+//! `nightly` feature for anyone who wants it to "just work" with any
+//! `std::str::pattern::Pattern`, not only a [`char`] or `&str`.
+use std::str::pattern::{Pattern, ReverseSearcher};
+
+use crate::SplitError;
+
+/// Allows us to implement split_last on external types, such as [core::str].
+pub trait SplitLast<'a, P: Pattern<'a> + Copy> {
+    type Error;
+    /// Takes the same [Pattern] as all other split functions.
+    /// Example:
+    /// ```rust
+    /// use split_last::PatternSplitLast;
+    ///
+    /// let result = "/some/simple/test".split_last('/').expect("oops");
+    /// assert_eq!(result, "test");
+    ///
+    /// let result = "some/simple/test/with_trailing/".split_last('/').expect("oops");
+    /// assert_eq!(result, "with_trailing");
+    ///```
+    fn split_last(&'a self, pat: P) -> Result<&str, Self::Error>
+    where
+        <P as Pattern<'a>>::Searcher: ReverseSearcher<'a>;
+
+    /// Like [`SplitLast::split_last`], but also returns everything before the
+    /// final delimiter, analogous to [`str::rsplit_once`].
+    /// Example:
+    /// ```rust
+    /// use split_last::PatternSplitLast;
+    ///
+    /// let (prefix, last) = "/some/long/test/".split_last_once('/').expect("oops");
+    /// assert_eq!(prefix, "/some/long");
+    /// assert_eq!(last, "test");
+    ///```
+    fn split_last_once(&'a self, pat: P) -> Result<(&str, &str), Self::Error>
+    where
+        <P as Pattern<'a>>::Searcher: ReverseSearcher<'a>;
+}
+
+impl<'a, P: Pattern<'a> + Copy> SplitLast<'a, P> for &str {
+    type Error = SplitError;
+
+    #[inline]
+    fn split_last(&'a self, pat: P) -> Result<&str, Self::Error>
+    where
+        <P as Pattern<'a>>::Searcher: ReverseSearcher<'a>,
+    {
+        // This just lets us strip off any trailing patterns.  Else
+        // split_last would return an empty string.
+        let target = match pat.strip_suffix_of(self) {
+            Some(target) => target,
+            None => self,
+        };
+
+        target
+            .split(pat)
+            .last()
+            .ok_or_else(|| SplitError("Failed to split".to_string()))
+    }
+
+    #[inline]
+    fn split_last_once(&'a self, pat: P) -> Result<(&str, &str), Self::Error>
+    where
+        <P as Pattern<'a>>::Searcher: ReverseSearcher<'a>,
+    {
+        let target = match pat.strip_suffix_of(self) {
+            Some(target) => target,
+            None => self,
+        };
+
+        target
+            .rsplit_once(pat)
+            .ok_or_else(|| SplitError("Failed to split".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_it() {
+        let result = "test".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "test/".split_last("/").expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "/test".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "/test/".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "/some/long//test/".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+    }
+
+    #[test]
+    fn test_split_last_once() {
+        let (prefix, last) = "/some/long/test/".split_last_once('/').expect("oops");
+        assert_eq!(prefix, "/some/long");
+        assert_eq!(last, "test");
+    }
+}
@@ -0,0 +1,97 @@
+//! `split_last` for [`OsStr`], following the approach taken by the
+//! `os_str_bytes` crate: scan the raw, not-necessarily-UTF-8 encoded bytes
+//! directly rather than going through `&str`.
+use std::ffi::OsStr;
+
+use crate::{SplitError, SplitLast, SplitPattern};
+
+impl<'a, P> SplitLast<'a, P> for &'a OsStr
+where
+    P: Into<SplitPattern<'a>>,
+{
+    type Error = SplitError;
+    type Output = OsStr;
+
+    #[inline]
+    fn split_last(&'a self, pat: P) -> Result<&'a OsStr, Self::Error> {
+        let pattern = pat.into();
+        let bytes = self.as_encoded_bytes();
+
+        let target = pattern.strip_suffix_of_bytes(bytes);
+        let last = match pattern.rfind_match_bytes(target) {
+            Some((_, end)) => &target[end..],
+            None => target,
+        };
+
+        // SAFETY: `last` is a suffix of `bytes` split on a UTF-8 boundary -
+        // the delimiter is always valid UTF-8, so both sides of the split
+        // remain valid `OsStr` encodings.
+        Ok(unsafe { OsStr::from_encoded_bytes_unchecked(last) })
+    }
+
+    #[inline]
+    fn split_last_once(&'a self, pat: P) -> Result<(&'a OsStr, &'a OsStr), Self::Error> {
+        let pattern = pat.into();
+        let bytes = self.as_encoded_bytes();
+
+        let target = pattern.strip_suffix_of_bytes(bytes);
+        match pattern.rfind_match_bytes(target) {
+            // SAFETY: see `split_last` above.
+            Some((start, end)) => Ok(unsafe {
+                (
+                    OsStr::from_encoded_bytes_unchecked(&target[..start]),
+                    OsStr::from_encoded_bytes_unchecked(&target[end..]),
+                )
+            }),
+            None => Err(SplitError("Failed to split".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_os_str_split_last() {
+        let path = OsStr::new("/some/long/test/");
+        let result = (&path).split_last('/').expect("oops");
+        assert_eq!(result, OsStr::new("test"));
+
+        let name = OsStr::new("file.txt");
+        let result = (&name).split_last('/').expect("oops");
+        assert_eq!(result, OsStr::new("file.txt"));
+    }
+
+    #[test]
+    fn test_os_str_split_last_once() {
+        let path = OsStr::new("/some/long/test/");
+        let (prefix, last) = (&path).split_last_once('/').expect("oops");
+        assert_eq!(prefix, OsStr::new("/some/long"));
+        assert_eq!(last, OsStr::new("test"));
+    }
+
+    // Exercises the non-UTF-8 path the `unsafe` blocks above actually exist
+    // for: an `OsStr` built from raw bytes that aren't valid UTF-8 on either
+    // side of the delimiter.
+    #[cfg(unix)]
+    #[test]
+    fn test_os_str_split_last_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let raw = OsStr::from_bytes(b"\xFF\xFE/\xFD\xFC");
+        let result = (&raw).split_last('/').expect("oops");
+        assert_eq!(result.as_bytes(), b"\xFD\xFC");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_os_str_split_last_once_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let raw = OsStr::from_bytes(b"\xFF\xFE/\xFD\xFC");
+        let (prefix, last) = (&raw).split_last_once('/').expect("oops");
+        assert_eq!(prefix.as_bytes(), b"\xFF\xFE");
+        assert_eq!(last.as_bytes(), b"\xFD\xFC");
+    }
+}
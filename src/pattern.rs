@@ -0,0 +1,92 @@
+//! A stable stand-in for `std::str::pattern::Pattern`.
+//!
+//! The real `Pattern` trait is nightly-only, so the default (stable) code path
+//! can't be generic over it. Instead, callers hand us a [`char`] or `&str`
+//! delimiter, which we convert into a [`SplitPattern`] and match on directly.
+
+/// The delimiter shapes supported by the stable [`SplitLast`](crate::SplitLast) impls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPattern<'a> {
+    /// Split on a single character.
+    Char(char),
+    /// Split on a string delimiter.
+    Str(&'a str),
+}
+
+impl<'a> From<char> for SplitPattern<'a> {
+    fn from(c: char) -> Self {
+        SplitPattern::Char(c)
+    }
+}
+
+impl<'a> From<&'a str> for SplitPattern<'a> {
+    fn from(s: &'a str) -> Self {
+        SplitPattern::Str(s)
+    }
+}
+
+impl<'a> SplitPattern<'a> {
+    /// Strip a single trailing occurrence of this pattern from `haystack`, if present.
+    pub(crate) fn strip_suffix_of<'b>(&self, haystack: &'b str) -> &'b str {
+        match self {
+            SplitPattern::Char(c) => haystack.strip_suffix(*c).unwrap_or(haystack),
+            SplitPattern::Str(s) if !s.is_empty() => {
+                haystack.strip_suffix(*s).unwrap_or(haystack)
+            }
+            SplitPattern::Str(_) => haystack,
+        }
+    }
+
+    /// Find the last occurrence of this pattern in `haystack`, returning the
+    /// `(start, end)` byte range of the match itself.
+    pub(crate) fn rfind_match(&self, haystack: &str) -> Option<(usize, usize)> {
+        match self {
+            SplitPattern::Char(c) => haystack
+                .rfind(*c)
+                .map(|start| (start, start + c.len_utf8())),
+            SplitPattern::Str(s) if !s.is_empty() => {
+                haystack.rfind(*s).map(|start| (start, start + s.len()))
+            }
+            SplitPattern::Str(_) => None,
+        }
+    }
+
+    /// The UTF-8 encoding of this delimiter, for matching against raw bytes
+    /// (e.g. the encoded bytes of an [`std::ffi::OsStr`]).
+    pub(crate) fn as_bytes<'b>(&self, buf: &'b mut [u8; 4]) -> &'b [u8]
+    where
+        'a: 'b,
+    {
+        match self {
+            SplitPattern::Char(c) => c.encode_utf8(buf).as_bytes(),
+            SplitPattern::Str(s) => s.as_bytes(),
+        }
+    }
+
+    /// Byte-slice equivalent of [`Self::strip_suffix_of`], for haystacks that
+    /// aren't necessarily valid UTF-8.
+    pub(crate) fn strip_suffix_of_bytes<'b>(&self, haystack: &'b [u8]) -> &'b [u8] {
+        let mut buf = [0u8; 4];
+        let delim = self.as_bytes(&mut buf);
+        if delim.is_empty() {
+            return haystack;
+        }
+        match haystack.len().checked_sub(delim.len()) {
+            Some(start) if &haystack[start..] == delim => &haystack[..start],
+            _ => haystack,
+        }
+    }
+
+    /// Byte-slice equivalent of [`Self::rfind_match`].
+    pub(crate) fn rfind_match_bytes(&self, haystack: &[u8]) -> Option<(usize, usize)> {
+        let mut buf = [0u8; 4];
+        let delim = self.as_bytes(&mut buf);
+        if delim.is_empty() || delim.len() > haystack.len() {
+            return None;
+        }
+        (0..=haystack.len() - delim.len())
+            .rev()
+            .find(|&start| &haystack[start..start + delim.len()] == delim)
+            .map(|start| (start, start + delim.len()))
+    }
+}
@@ -0,0 +1,112 @@
+//! The default, stable-Rust implementation. Instead of `std::str::pattern::Pattern`,
+//! delimiters go through [`SplitPattern`] so the crate builds without nightly.
+use crate::{SplitError, SplitPattern};
+
+/// Allows us to implement split_last on external types, such as [core::str]
+/// and [`std::ffi::OsStr`].
+pub trait SplitLast<'a, P>
+where
+    P: Into<SplitPattern<'a>>,
+{
+    type Error;
+    /// The borrowed slice type returned by this impl, e.g. `str` for `&str`
+    /// and `OsStr` for `&OsStr`.
+    type Output: ?Sized;
+
+    /// Takes a [`char`] or `&str` delimiter, the same as the nightly `Pattern`-based API.
+    /// Example:
+    /// ```rust
+    /// use split_last::SplitLast;
+    ///
+    /// let result = "/some/simple/test".split_last('/').expect("oops");
+    /// assert_eq!(result, "test");
+    ///
+    /// let result = "some/simple/test/with_trailing/".split_last('/').expect("oops");
+    /// assert_eq!(result, "with_trailing");
+    ///```
+    fn split_last(&'a self, pat: P) -> Result<&'a Self::Output, Self::Error>;
+
+    /// Like [`SplitLast::split_last`], but also returns everything before the
+    /// final delimiter, analogous to [`str::rsplit_once`].
+    /// Example:
+    /// ```rust
+    /// use split_last::SplitLast;
+    ///
+    /// let (prefix, last) = "/some/long/test/".split_last_once('/').expect("oops");
+    /// assert_eq!(prefix, "/some/long");
+    /// assert_eq!(last, "test");
+    ///```
+    fn split_last_once(&'a self, pat: P) -> Result<(&'a Self::Output, &'a Self::Output), Self::Error>;
+}
+
+impl<'a, P> SplitLast<'a, P> for &'a str
+where
+    P: Into<SplitPattern<'a>>,
+{
+    type Error = SplitError;
+    type Output = str;
+
+    #[inline]
+    fn split_last(&'a self, pat: P) -> Result<&'a str, Self::Error> {
+        let pattern = pat.into();
+
+        // This just lets us strip off any trailing patterns.  Else
+        // split_last would return an empty string.
+        let target = pattern.strip_suffix_of(self);
+
+        let last = match pattern.rfind_match(target) {
+            Some((_, end)) => &target[end..],
+            None => target,
+        };
+
+        Ok(last)
+    }
+
+    #[inline]
+    fn split_last_once(&'a self, pat: P) -> Result<(&'a str, &'a str), Self::Error> {
+        let pattern = pat.into();
+        let target = pattern.strip_suffix_of(self);
+
+        match pattern.rfind_match(target) {
+            Some((start, end)) => Ok((&target[..start], &target[end..])),
+            None => Err(SplitError("Failed to split".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_it() {
+        let result = "test".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "test/".split_last("/").expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "/test".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "/test/".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+
+        let result = "/some/long//test/".split_last('/').expect("oops");
+        assert_eq!(result, "test");
+    }
+
+    #[test]
+    fn test_split_last_once() {
+        let (prefix, last) = "/some/long/test/".split_last_once('/').expect("oops");
+        assert_eq!(prefix, "/some/long");
+        assert_eq!(last, "test");
+
+        let (prefix, last) = "a/b/c".split_last_once('/').expect("oops");
+        assert_eq!(prefix, "a/b");
+        assert_eq!(last, "c");
+
+        let err = "no-delimiter".split_last_once('/');
+        assert!(err.is_err());
+    }
+}